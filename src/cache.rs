@@ -0,0 +1,150 @@
+//! On-disk caching of fetched resources, using HTTP conditional requests
+//! (`If-None-Match` / `If-Modified-Since`) to avoid redundant downloads.
+
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::error::Error;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+fn blocking_client() -> &'static reqwest::blocking::Client {
+    static CLIENT: OnceLock<reqwest::blocking::Client> = OnceLock::new();
+    CLIENT.get_or_init(reqwest::blocking::Client::new)
+}
+
+/// Sidecar metadata persisted alongside a cached resource's bytes.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheMeta {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+/// An on-disk cache of fetched resources, keyed by URL, that uses `ETag`/
+/// `Last-Modified` conditional requests to avoid re-downloading unchanged resources.
+///
+/// Unlike the plain [`crate::get_resource`]/[`crate::load_all_configs_from_url`],
+/// which always hit the network, a `ResourceCache` is explicit per-call state: create
+/// one (typically pointed at a directory the caller owns) and pass it to
+/// [`crate::get_resource_cached`]/[`crate::load_all_configs_from_url_cached`].
+///
+/// # Example
+/// ```no_run
+/// use etf_matcher_vector_config_loader::{get_resource_cached, ResourceCache};
+/// let cache = ResourceCache::new("./.etf_matcher_cache");
+/// let data = get_resource_cached("sample.bin", &cache).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResourceCache {
+    dir: PathBuf,
+}
+
+impl ResourceCache {
+    /// Creates a cache rooted at `dir`. The directory is created lazily on first use.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Removes this cache's directory together with all entries in it. A no-op if
+    /// the directory doesn't exist.
+    pub fn clear(&self) -> std::io::Result<()> {
+        if self.dir.exists() {
+            std::fs::remove_dir_all(&self.dir)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn paths_for(&self, url: &str) -> (PathBuf, PathBuf) {
+        let mut hasher = DefaultHasher::new();
+        url.hash(&mut hasher);
+        let data_path = self.dir.join(format!("{:x}.bin", hasher.finish()));
+        let meta_path = data_path.with_extension("meta.toml");
+        (data_path, meta_path)
+    }
+
+    /// Fetches `url`, transparently caching the response on disk and issuing a
+    /// conditional request on subsequent calls so unchanged resources aren't
+    /// re-downloaded.
+    ///
+    /// A cache miss, missing or unparseable metadata, or a data file that can't be
+    /// read falls back cleanly to a full download. The cached bytes themselves are not
+    /// validated: a readable data file is returned as-is on a `304 Not Modified`.
+    pub(crate) fn fetch_bytes(&self, url: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+        std::fs::create_dir_all(&self.dir)?;
+        let (data_path, meta_path) = self.paths_for(url);
+        let meta = read_meta(&meta_path);
+
+        let client = blocking_client();
+        let mut request = client.get(url);
+        if let Some(etag) = &meta.etag {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+        if let Some(last_modified) = &meta.last_modified {
+            request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+        }
+
+        let response = request.send()?;
+
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            if let Ok(cached) = std::fs::read(&data_path) {
+                return Ok(cached);
+            }
+            // Cached data file is missing or unreadable despite a 304 - fall back to a full download.
+            let response = client.get(url).send()?;
+            return store(ensure_success(response, url)?, &data_path, &meta_path);
+        }
+
+        store(ensure_success(response, url)?, &data_path, &meta_path)
+    }
+}
+
+/// Rejects a non-2xx response instead of letting it be cached and replayed as if it
+/// were valid resource bytes (e.g. a transient 500, or a 404 with its own cache
+/// headers).
+fn ensure_success(
+    response: reqwest::blocking::Response,
+    url: &str,
+) -> Result<reqwest::blocking::Response, Box<dyn Error>> {
+    if response.status().is_success() {
+        Ok(response)
+    } else {
+        Err(format!("unexpected HTTP status {} fetching {}", response.status(), url).into())
+    }
+}
+
+fn read_meta(meta_path: &Path) -> CacheMeta {
+    std::fs::read_to_string(meta_path)
+        .ok()
+        .and_then(|text| toml::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+fn store(
+    response: reqwest::blocking::Response,
+    data_path: &Path,
+    meta_path: &Path,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    let etag = response
+        .headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+    let last_modified = response
+        .headers()
+        .get(reqwest::header::LAST_MODIFIED)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+
+    let bytes = response.bytes()?.to_vec();
+
+    std::fs::write(data_path, &bytes)?;
+    if let Ok(serialized) = toml::to_string(&CacheMeta {
+        etag,
+        last_modified,
+    }) {
+        let _ = std::fs::write(meta_path, serialized);
+    }
+
+    Ok(bytes)
+}
@@ -0,0 +1,81 @@
+//! Local config discovery, mirroring how Anchor's `Config::discover` walks parent
+//! directories looking for `Anchor.toml`.
+
+use crate::{get_all_etf_matcher_configs, Config, TickerVectorConfigMap};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+/// Filename `discover_local_config` looks for while walking up from the current
+/// working directory.
+const LOCAL_CONFIG_FILENAME: &str = "etf_matcher_vector_configs.toml";
+
+/// Walks up from `start` looking for `etf_matcher_vector_configs.toml`, returning its
+/// path as soon as one is found. Takes an explicit starting directory (rather than
+/// always reading the process's current directory) so the walk can be tested without
+/// touching global process state.
+///
+/// # Returns
+/// * `Ok(Some(path))` if a local config file was found in `start` or one of its
+///   ancestors.
+/// * `Ok(None)` if no ancestor directory contains the file.
+pub fn discover_local_config_from(start: impl AsRef<Path>) -> Result<Option<PathBuf>, Box<dyn Error>> {
+    let mut dir = start.as_ref().to_path_buf();
+
+    loop {
+        let candidate = dir.join(LOCAL_CONFIG_FILENAME);
+        if candidate.is_file() {
+            return Ok(Some(candidate));
+        }
+        if !dir.pop() {
+            return Ok(None);
+        }
+    }
+}
+
+/// Walks up from the current working directory looking for `etf_matcher_vector_configs.toml`.
+/// See [`discover_local_config_from`] for the underlying walk.
+///
+/// # Returns
+/// * `Ok(Some(path))` if a local config file was found in the current directory or
+///   one of its ancestors.
+/// * `Ok(None)` if no ancestor directory contains the file.
+/// * `Err(Box<dyn std::error::Error>)` if the current working directory can't be read.
+pub fn discover_local_config() -> Result<Option<PathBuf>, Box<dyn Error>> {
+    discover_local_config_from(std::env::current_dir()?)
+}
+
+/// Loads a local config file through the same TOML parsing path used for the remote
+/// config.
+fn load_local_config(path: &Path) -> Result<TickerVectorConfigMap, Box<dyn Error>> {
+    let text = std::fs::read_to_string(path)?;
+    let config: Config = toml::from_str(&text)?;
+    Ok(config.ticker_vector_config)
+}
+
+/// Prefers a local `etf_matcher_vector_configs.toml` discovered under `start` (see
+/// [`discover_local_config_from`]), falling back to the remote `BASE_URL` config when
+/// none is found. Takes an explicit starting directory so this can be exercised
+/// end-to-end, offline, against a fixture directory in tests.
+pub fn get_all_etf_matcher_configs_or_discover_from(
+    start: impl AsRef<Path>,
+) -> Result<TickerVectorConfigMap, Box<dyn Error>> {
+    match discover_local_config_from(start)? {
+        Some(path) => load_local_config(&path),
+        None => get_all_etf_matcher_configs(),
+    }
+}
+
+/// Prefers a locally discovered `etf_matcher_vector_configs.toml` (see
+/// [`discover_local_config`]), falling back to the remote `BASE_URL` config when none
+/// is found. Enables fully offline development and reproducible tests that don't
+/// depend on `etfmatcher.com` being reachable.
+///
+/// # Example
+/// ```no_run
+/// use etf_matcher_vector_config_loader::get_all_etf_matcher_configs_or_discover;
+/// let configs = get_all_etf_matcher_configs_or_discover().unwrap();
+/// println!("Loaded {} configurations", configs.len());
+/// ```
+pub fn get_all_etf_matcher_configs_or_discover() -> Result<TickerVectorConfigMap, Box<dyn Error>> {
+    get_all_etf_matcher_configs_or_discover_from(std::env::current_dir()?)
+}
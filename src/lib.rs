@@ -3,14 +3,41 @@ doc_comment::doctest!("../README.md");
 
 use reqwest;
 use serde::Deserialize;
+use sha2::Digest;
 use std::collections::BTreeMap;
 use std::error::Error;
+#[cfg(feature = "async")]
+use std::sync::OnceLock;
 
-static BASE_URL: &str = "https://etfmatcher.com/data/";
+pub(crate) static BASE_URL: &str = "https://etfmatcher.com/data/";
+
+mod loader;
+pub use loader::ConfigLoader;
+
+mod watcher;
+pub use watcher::{ConfigChange, ConfigDiff, ConfigWatcher};
+
+mod cache;
+pub use cache::ResourceCache;
+
+mod discover;
+pub use discover::{
+    discover_local_config, discover_local_config_from, get_all_etf_matcher_configs_or_discover,
+    get_all_etf_matcher_configs_or_discover_from,
+};
+
+/// Returns a process-wide [`reqwest::Client`] shared by all `_async` functions so that
+/// connection pooling is reused across consecutive requests (e.g. fetching the config
+/// TOML followed by several vector collections).
+#[cfg(feature = "async")]
+fn async_client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(reqwest::Client::new)
+}
 
 /// Represents the configuration for a ticker vector file.
 /// This struct is deserialized from the TOML configuration file.
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct TickerVectorConfig {
     /// File path of the ticker vector.
     pub path: String,
@@ -28,6 +55,13 @@ pub struct TickerVectorConfig {
     pub training_sequence_length: Option<u32>,
     /// List of data sources used for training.
     pub training_data_sources: Option<Vec<String>>,
+    /// Expected SHA-256 checksum (lowercase hex) of the resource at `path`, used by
+    /// [`get_ticker_vectors_collection_by_key_verified`] to detect truncated or
+    /// corrupted downloads. `None` if the remote config omits it.
+    pub sha256: Option<String>,
+    /// Expected size in bytes of the resource at `path`, checked by
+    /// [`get_ticker_vectors_collection_by_key_verified`] when present.
+    pub size_bytes: Option<u64>,
 }
 
 pub type TickerVectorConfigMap = BTreeMap<String, TickerVectorConfig>;
@@ -56,6 +90,27 @@ pub fn get_all_etf_matcher_configs() -> Result<TickerVectorConfigMap, Box<dyn st
     load_all_configs_from_url(&format!("{}ticker_vector_configs.toml", BASE_URL))
 }
 
+/// Async twin of [`get_all_etf_matcher_configs`]. Requires the `async` feature.
+///
+/// # Returns
+/// * `Ok(TickerVectorConfigMap)` if the request succeeds.
+/// * `Err(Box<dyn std::error::Error>)` if the request fails.
+///
+/// # Example
+/// ```no_run
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// use etf_matcher_vector_config_loader::get_all_etf_matcher_configs_async;
+/// let configs = get_all_etf_matcher_configs_async().await?;
+/// println!("Loaded {} configurations", configs.len());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "async")]
+pub async fn get_all_etf_matcher_configs_async(
+) -> Result<TickerVectorConfigMap, Box<dyn std::error::Error>> {
+    load_all_configs_from_url_async(&format!("{}ticker_vector_configs.toml", BASE_URL)).await
+}
+
 /// Fetches a specific ETF Matcher ticker vector configuration by key.
 ///
 /// # Arguments
@@ -82,6 +137,26 @@ pub fn get_etf_matcher_config_by_key(
     Ok(selected_config.clone())
 }
 
+/// Async twin of [`get_etf_matcher_config_by_key`]. Requires the `async` feature.
+///
+/// # Arguments
+/// * `key` - The name of the configuration to retrieve.
+///
+/// # Returns
+/// * `Ok(TickerVectorConfig)` if the key exists.
+/// * `Err(Box<dyn std::error::Error>)` if the key is not found.
+#[cfg(feature = "async")]
+pub async fn get_etf_matcher_config_by_key_async(
+    key: &str,
+) -> Result<TickerVectorConfig, Box<dyn std::error::Error>> {
+    let all_configs = get_all_etf_matcher_configs_async().await?;
+
+    let selected_config = get_config_by_key(&all_configs, key)
+        .ok_or_else(|| format!("Config for key '{}' not found", key))?;
+
+    Ok(selected_config.clone())
+}
+
 /// Fetches the ticker vectors collection using a specific ETF Matcher configuration key.
 ///
 /// # Arguments
@@ -91,6 +166,11 @@ pub fn get_etf_matcher_config_by_key(
 /// * `Ok(Vec<u8>)` containing the binary data.
 /// * `Err(Box<dyn std::error::Error>)` if fetching fails.
 ///
+/// If the config has a `sha256` checksum, the downloaded bytes are verified against
+/// it (failing with a descriptive error on mismatch); if it doesn't, verification is
+/// skipped. Use [`get_ticker_vectors_collection_by_key_verified`] to require a
+/// checksum rather than silently skipping verification when one is absent.
+///
 /// # Example
 /// ```
 /// use etf_matcher_vector_config_loader::get_ticker_vectors_collection_by_key;
@@ -104,7 +184,102 @@ pub fn get_ticker_vectors_collection_by_key(
     let config = get_etf_matcher_config_by_key(key)?;
 
     // Fetch the ticker vectors collection file using the config path
-    get_resource(&config.path)
+    let bytes = get_resource(&config.path)?;
+
+    if let Some(expected_sha256) = &config.sha256 {
+        verify_resource(&bytes, expected_sha256)?;
+    }
+
+    Ok(bytes)
+}
+
+/// Computes the lowercase-hex SHA-256 digest of `bytes` and compares it against
+/// `expected_sha256` (case-insensitive).
+///
+/// # Returns
+/// * `Ok(())` if the digest matches.
+/// * `Err(Box<dyn std::error::Error>)` describing the mismatch otherwise.
+pub fn verify_resource(bytes: &[u8], expected_sha256: &str) -> Result<(), Box<dyn Error>> {
+    let digest = sha2::Sha256::digest(bytes);
+    let actual: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+
+    if actual.eq_ignore_ascii_case(expected_sha256) {
+        Ok(())
+    } else {
+        Err(format!(
+            "checksum mismatch: expected sha256 {}, got {} ({} bytes)",
+            expected_sha256,
+            actual,
+            bytes.len()
+        )
+        .into())
+    }
+}
+
+/// Fetches the ticker vectors collection for `key`, as
+/// [`get_ticker_vectors_collection_by_key`] does, but additionally requires and
+/// verifies the config's `sha256` (and `size_bytes`, if present) before returning.
+///
+/// # Arguments
+/// * `key` - The name of the configuration to retrieve.
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` if the download succeeds and matches the expected checksum.
+/// * `Err(Box<dyn std::error::Error>)` if fetching fails, the config has no `sha256`
+///   to verify against, or the checksum doesn't match.
+pub fn get_ticker_vectors_collection_by_key_verified(
+    key: &str,
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let config = get_etf_matcher_config_by_key(key)?;
+    let expected_sha256 = config.sha256.as_deref().ok_or_else(|| {
+        format!(
+            "Config for key '{}' has no sha256 checksum to verify against",
+            key
+        )
+    })?;
+
+    let bytes = get_resource(&config.path)?;
+
+    if let Some(expected_size) = config.size_bytes {
+        if bytes.len() as u64 != expected_size {
+            return Err(format!(
+                "size mismatch for key '{}': expected {} bytes, got {}",
+                key,
+                expected_size,
+                bytes.len()
+            )
+            .into());
+        }
+    }
+
+    verify_resource(&bytes, expected_sha256)?;
+
+    Ok(bytes)
+}
+
+/// Async twin of [`get_ticker_vectors_collection_by_key`]. Requires the `async` feature.
+///
+/// # Arguments
+/// * `key` - The name of the configuration to retrieve.
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` containing the binary data.
+/// * `Err(Box<dyn std::error::Error>)` if fetching fails.
+#[cfg(feature = "async")]
+pub async fn get_ticker_vectors_collection_by_key_async(
+    key: &str,
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    // Fetch the configuration by key
+    let config = get_etf_matcher_config_by_key_async(key).await?;
+
+    // Fetch the ticker vectors collection file using the config path
+    let bytes = get_resource_async(&config.path).await?;
+
+    if let Some(expected_sha256) = &config.sha256 {
+        verify_resource(&bytes, expected_sha256)?;
+    }
+
+    Ok(bytes)
 }
 
 /// Retrieves the fully qualified URL for the ticker symbol map file.
@@ -138,6 +313,16 @@ pub fn get_symbol_map() -> Result<Vec<u8>, Box<dyn std::error::Error>> {
     get_resource(&get_symbol_map_url())
 }
 
+/// Async twin of [`get_symbol_map`]. Requires the `async` feature.
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` containing the binary data.
+/// * `Err(Box<dyn std::error::Error>)` if the request fails.
+#[cfg(feature = "async")]
+pub async fn get_symbol_map_async() -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    get_resource_async(&get_symbol_map_url()).await
+}
+
 /// Constructs a fully qualified URL for a given filename.
 ///
 /// # Arguments
@@ -187,6 +372,51 @@ pub fn get_resource(path: &str) -> Result<Vec<u8>, Box<dyn Error>> {
     Ok(response.to_vec())
 }
 
+/// Cached twin of [`get_resource`], backed by an explicit [`ResourceCache`] rather
+/// than hitting the network on every call.
+///
+/// # Arguments
+/// * `path` - Either a filename (e.g., `"dataset.bin"`) or a full URL (`"https://example.com/data.bin"`).
+/// * `cache` - The on-disk cache to read from / write to.
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` containing the binary data.
+/// * `Err(Box<dyn std::error::Error>)` if the request fails.
+pub fn get_resource_cached(path: &str, cache: &ResourceCache) -> Result<Vec<u8>, Box<dyn Error>> {
+    // Check if the input looks like a full URL
+    let url = if path.starts_with("http://") || path.starts_with("https://") {
+        path.to_string() // Already an FQDN, use as-is
+    } else {
+        get_resource_url(path) // It's a filename, construct full URL
+    };
+
+    cache.fetch_bytes(&url)
+}
+
+/// Async twin of [`get_resource`]. Requires the `async` feature.
+///
+/// Uses a shared, process-wide [`reqwest::Client`] so consecutive calls (e.g. fetching
+/// the config TOML followed by several vector collections) reuse the same connection pool.
+///
+/// # Arguments
+/// * `path` - Either a filename (e.g., `"dataset.bin"`) or a full URL (`"https://example.com/data.bin"`).
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` containing the binary data.
+/// * `Err(Box<dyn std::error::Error>)` if the request fails.
+#[cfg(feature = "async")]
+pub async fn get_resource_async(path: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    // Check if the input looks like a full URL
+    let url = if path.starts_with("http://") || path.starts_with("https://") {
+        path.to_string() // Already an FQDN, use as-is
+    } else {
+        get_resource_url(path) // It's a filename, construct full URL
+    };
+
+    let response = async_client().get(url).send().await?.bytes().await?;
+    Ok(response.to_vec())
+}
+
 /// Fetches the ETF Matcher ticker vector configurations from a remote TOML file.
 ///
 /// # Arguments
@@ -215,6 +445,50 @@ pub fn load_all_configs_from_url(
     Ok(config.ticker_vector_config)
 }
 
+/// Cached twin of [`load_all_configs_from_url`], backed by an explicit
+/// [`ResourceCache`] rather than hitting the network on every call.
+///
+/// # Arguments
+/// * `url` - The URL of the TOML configuration file.
+/// * `cache` - The on-disk cache to read from / write to.
+///
+/// # Returns
+/// * `Ok(TickerVectorConfigMap)` on success.
+/// * `Err(Box<dyn std::error::Error>)` if the request fails or the TOML parsing fails.
+pub fn load_all_configs_from_url_cached(
+    url: &str,
+    cache: &ResourceCache,
+) -> Result<TickerVectorConfigMap, Box<dyn std::error::Error>> {
+    let bytes = cache.fetch_bytes(url)?;
+    let response = String::from_utf8(bytes)?;
+
+    let config: Config = toml::from_str(&response)?;
+
+    Ok(config.ticker_vector_config)
+}
+
+/// Async twin of [`load_all_configs_from_url`]. Requires the `async` feature.
+///
+/// # Arguments
+/// * `url` - The URL of the TOML configuration file.
+///
+/// # Returns
+/// * `Ok(TickerVectorConfigMap)` on success.
+/// * `Err(Box<dyn std::error::Error>)` if the request fails or the TOML parsing fails.
+#[cfg(feature = "async")]
+pub async fn load_all_configs_from_url_async(
+    url: &str,
+) -> Result<TickerVectorConfigMap, Box<dyn std::error::Error>> {
+    // Fetch the TOML file from the remote URL.
+    let response = async_client().get(url).send().await?.text().await?;
+
+    // Parse the TOML content into a Config struct.
+    let config: Config = toml::from_str(&response)?;
+
+    // Return all configurations as a BTreeMap.
+    Ok(config.ticker_vector_config)
+}
+
 /// Retrieves a specific configuration from the loaded ETF Matcher configurations.
 ///
 /// # Arguments
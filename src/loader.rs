@@ -0,0 +1,121 @@
+//! Layered configuration loading: remote TOML, optional local TOML overrides, and
+//! environment variable overrides, merged in that priority order.
+
+use crate::{load_all_configs_from_url, Config, TickerVectorConfigMap, BASE_URL};
+use std::error::Error;
+use std::path::PathBuf;
+
+/// Name of the environment variable that overrides [`BASE_URL`] when resolving the
+/// remote `ticker_vector_configs.toml`.
+const BASE_URL_ENV: &str = "ETF_MATCHER_BASE_URL";
+
+/// Prefix for per-key field overrides, e.g. `ETF_MATCHER__DEFAULT__PATH`.
+const FIELD_OVERRIDE_PREFIX: &str = "ETF_MATCHER__";
+
+/// Builds a [`TickerVectorConfigMap`] by merging, in increasing precedence order:
+/// the remote `ticker_vector_configs.toml`, an optional local TOML file, and
+/// environment variable overrides.
+///
+/// # Example
+/// ```no_run
+/// use etf_matcher_vector_config_loader::ConfigLoader;
+/// let configs = ConfigLoader::new()
+///     .local_path("./ticker_vector_configs.local.toml")
+///     .load()
+///     .unwrap();
+/// println!("Loaded {} configurations", configs.len());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct ConfigLoader {
+    base_url: Option<String>,
+    local_path: Option<PathBuf>,
+}
+
+impl ConfigLoader {
+    /// Creates a loader with no overrides; `load()` behaves like
+    /// [`crate::get_all_etf_matcher_configs`] plus any environment overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the remote base URL, taking precedence over the `ETF_MATCHER_BASE_URL`
+    /// environment variable and the built-in default.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets a local TOML file whose entries are merged on top of the remote map.
+    /// Ignored if the path does not exist at load time.
+    pub fn local_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.local_path = Some(path.into());
+        self
+    }
+
+    /// Resolves the base URL to use for the remote fetch: explicit override, then
+    /// `ETF_MATCHER_BASE_URL`, then the crate default.
+    fn resolved_base_url(&self) -> String {
+        self.base_url
+            .clone()
+            .or_else(|| std::env::var(BASE_URL_ENV).ok())
+            .unwrap_or_else(|| BASE_URL.to_string())
+    }
+
+    /// Fetches the remote map, merges in the local TOML file (if present), then applies
+    /// environment variable field overrides, and returns the resulting map.
+    pub fn load(self) -> Result<TickerVectorConfigMap, Box<dyn Error>> {
+        let base_url = self.resolved_base_url();
+        let mut configs =
+            load_all_configs_from_url(&format!("{}ticker_vector_configs.toml", base_url))?;
+
+        if let Some(path) = &self.local_path {
+            if path.exists() {
+                let text = std::fs::read_to_string(path)?;
+                let local: Config = toml::from_str(&text)?;
+                configs.extend(local.ticker_vector_config);
+            }
+        }
+
+        apply_env_overrides(&mut configs)?;
+
+        Ok(configs)
+    }
+}
+
+/// Patches individual fields of `configs` from `ETF_MATCHER__<KEY>__<FIELD>` environment
+/// variables, where `<KEY>` matches a config key case-insensitively. Supported fields are
+/// `PATH` and `VECTOR_DIMENSIONS`.
+fn apply_env_overrides(configs: &mut TickerVectorConfigMap) -> Result<(), Box<dyn Error>> {
+    for (env_key, value) in std::env::vars() {
+        let Some(rest) = env_key.strip_prefix(FIELD_OVERRIDE_PREFIX) else {
+            continue;
+        };
+        let Some((key_part, field_part)) = rest.rsplit_once("__") else {
+            continue;
+        };
+
+        let Some(config_key) = configs
+            .keys()
+            .find(|k| k.to_uppercase() == key_part.to_uppercase())
+            .cloned()
+        else {
+            continue;
+        };
+        let config = configs.get_mut(&config_key).expect("key was just found");
+
+        match field_part.to_uppercase().as_str() {
+            "PATH" => config.path = value,
+            "VECTOR_DIMENSIONS" => {
+                config.vector_dimensions = Some(value.parse().map_err(|e| {
+                    format!(
+                        "invalid value for {} (expected a u32): {}",
+                        env_key, e
+                    )
+                })?);
+            }
+            _ => continue,
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,184 @@
+//! Background hot-reload of the remote ticker vector config, with change
+//! notifications delivered over a channel.
+
+use crate::{load_all_configs_from_url, TickerVectorConfig, TickerVectorConfigMap};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// A single change between two successive [`TickerVectorConfigMap`] snapshots.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigChange {
+    /// A key present in the new snapshot but not the previous one.
+    Added(String, TickerVectorConfig),
+    /// A key present in the previous snapshot but not the new one.
+    Removed(String, TickerVectorConfig),
+    /// A key present in both snapshots whose config differs.
+    Changed {
+        key: String,
+        old: TickerVectorConfig,
+        new: TickerVectorConfig,
+    },
+}
+
+/// An ordered set of changes between two snapshots, sent to watchers whenever the
+/// remote config changes.
+pub type ConfigDiff = Vec<ConfigChange>;
+
+/// Computes the diff needed to go from `old` to `new`, in key order.
+fn diff_configs(old: &TickerVectorConfigMap, new: &TickerVectorConfigMap) -> ConfigDiff {
+    let mut changes = Vec::new();
+
+    for (key, new_config) in new {
+        match old.get(key) {
+            None => changes.push(ConfigChange::Added(key.clone(), new_config.clone())),
+            Some(old_config) if old_config != new_config => changes.push(ConfigChange::Changed {
+                key: key.clone(),
+                old: old_config.clone(),
+                new: new_config.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+
+    for (key, old_config) in old {
+        if !new.contains_key(key) {
+            changes.push(ConfigChange::Removed(key.clone(), old_config.clone()));
+        }
+    }
+
+    changes
+}
+
+fn hash_configs(configs: &TickerVectorConfigMap) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    format!("{:?}", configs).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Periodically re-fetches the remote `ticker_vector_configs.toml` on a background
+/// thread, keeping the last-known-good [`TickerVectorConfigMap`] and notifying a
+/// channel of whatever changed.
+///
+/// A failed fetch never clobbers the last-good snapshot. The first successful fetch
+/// always emits an "all added" diff.
+///
+/// # Example
+/// ```no_run
+/// use etf_matcher_vector_config_loader::ConfigWatcher;
+/// use std::time::Duration;
+///
+/// let (mut watcher, changes) = ConfigWatcher::new(
+///     "https://etfmatcher.com/data/ticker_vector_configs.toml",
+///     Duration::from_secs(300),
+/// );
+/// watcher.start();
+///
+/// if let Ok(diff) = changes.recv() {
+///     println!("Config changed: {:?}", diff);
+/// }
+///
+/// watcher.stop();
+/// ```
+pub struct ConfigWatcher {
+    url: String,
+    interval: Duration,
+    snapshot: Arc<Mutex<TickerVectorConfigMap>>,
+    hash: Arc<Mutex<Option<u64>>>,
+    sender: mpsc::Sender<ConfigDiff>,
+    stop_tx: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ConfigWatcher {
+    /// Creates a new watcher for `url`, polling every `interval`. Returns the watcher
+    /// and the receiving end of the channel that change diffs are delivered on.
+    pub fn new(url: impl Into<String>, interval: Duration) -> (Self, mpsc::Receiver<ConfigDiff>) {
+        let (sender, receiver) = mpsc::channel();
+        let watcher = Self {
+            url: url.into(),
+            interval,
+            snapshot: Arc::new(Mutex::new(TickerVectorConfigMap::new())),
+            hash: Arc::new(Mutex::new(None)),
+            sender,
+            stop_tx: None,
+            thread: None,
+        };
+        (watcher, receiver)
+    }
+
+    /// Spawns the background polling thread. Calling `start()` more than once without
+    /// an intervening `stop()` is a no-op; after a `stop()`, `start()` spawns a fresh
+    /// thread and polling resumes normally.
+    pub fn start(&mut self) {
+        if self.thread.is_some() {
+            return;
+        }
+
+        let (stop_tx, stop_rx) = mpsc::channel();
+        self.stop_tx = Some(stop_tx);
+
+        let url = self.url.clone();
+        let interval = self.interval;
+        let snapshot = Arc::clone(&self.snapshot);
+        let hash = Arc::clone(&self.hash);
+        let sender = self.sender.clone();
+
+        self.thread = Some(std::thread::spawn(move || loop {
+            if let Ok(fetched) = load_all_configs_from_url(&url) {
+                let new_hash = hash_configs(&fetched);
+                let mut last_hash = hash.lock().expect("watcher hash mutex poisoned");
+                let first_load = last_hash.is_none();
+
+                if *last_hash != Some(new_hash) {
+                    let mut current = snapshot.lock().expect("watcher snapshot mutex poisoned");
+                    let diff = diff_configs(&current, &fetched);
+                    *current = fetched;
+                    *last_hash = Some(new_hash);
+                    drop(current);
+                    drop(last_hash);
+
+                    if first_load || !diff.is_empty() {
+                        let _ = sender.send(diff);
+                    }
+                }
+            }
+            // A failed fetch simply leaves the last-good snapshot and hash in place.
+
+            // Waiting on the stop channel (rather than a plain `sleep`) lets `stop()`
+            // wake the loop immediately instead of blocking for up to `interval`.
+            match stop_rx.recv_timeout(interval) {
+                Ok(()) | Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                Err(mpsc::RecvTimeoutError::Timeout) => continue,
+            }
+        }));
+    }
+
+    /// Returns a cloned snapshot of the most recently fetched configuration map.
+    pub fn latest(&self) -> TickerVectorConfigMap {
+        self.snapshot
+            .lock()
+            .expect("watcher snapshot mutex poisoned")
+            .clone()
+    }
+
+    /// Signals the background thread to stop and waits for it to exit. Returns
+    /// promptly rather than waiting out the remainder of the current `interval`.
+    pub fn stop(&mut self) {
+        if let Some(stop_tx) = self.stop_tx.take() {
+            let _ = stop_tx.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
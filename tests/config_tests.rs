@@ -1,4 +1,5 @@
 use etf_matcher_vector_config_loader::*;
+use sha2::Digest;
 use std::collections::BTreeMap;
 
 #[test]
@@ -74,6 +75,8 @@ fn test_get_config_by_key() {
             vector_dimensions: Some(200),
             training_sequence_length: Some(50),
             training_data_sources: Some(vec!["source1".to_string(), "source2".to_string()]),
+            sha256: None,
+            size_bytes: None,
         },
     );
 
@@ -84,3 +87,206 @@ fn test_get_config_by_key() {
     let missing_config = get_config_by_key(&configs, "nonexistent");
     assert!(missing_config.is_none());
 }
+
+#[test]
+fn test_config_loader_merges_local_overrides() {
+    let dir = std::env::temp_dir();
+    let local_path = dir.join("etf_matcher_vector_config_loader_test_override.toml");
+    std::fs::write(
+        &local_path,
+        r#"
+[ticker_vector_config.test-local-override]
+path = "local_override.bin"
+"#,
+    )
+    .expect("failed to write local override file");
+
+    let configs = ConfigLoader::new()
+        .local_path(&local_path)
+        .load()
+        .expect("Failed to load layered configurations");
+
+    std::fs::remove_file(&local_path).ok();
+
+    let overridden = configs
+        .get("test-local-override")
+        .expect("Expected locally-defined key to be present after merge");
+    assert_eq!(overridden.path, "local_override.bin");
+}
+
+#[test]
+fn test_config_loader_applies_env_field_override() {
+    let configs = ConfigLoader::new()
+        .load()
+        .expect("Failed to fetch configurations");
+    let Some(existing_key) = configs.keys().next().cloned() else {
+        return;
+    };
+
+    std::env::set_var(
+        format!("ETF_MATCHER__{}__PATH", existing_key.to_uppercase()),
+        "patched.bin",
+    );
+    let configs = ConfigLoader::new()
+        .load()
+        .expect("Failed to fetch configurations with env override");
+    std::env::remove_var(format!("ETF_MATCHER__{}__PATH", existing_key.to_uppercase()));
+
+    assert_eq!(configs.get(&existing_key).unwrap().path, "patched.bin");
+}
+
+#[test]
+fn test_config_watcher_emits_initial_all_added_diff() {
+    let url = get_resource_url("ticker_vector_configs.toml");
+    let (mut watcher, changes) = ConfigWatcher::new(url, std::time::Duration::from_secs(60));
+    watcher.start();
+
+    let diff = changes
+        .recv_timeout(std::time::Duration::from_secs(30))
+        .expect("Expected an initial diff from the watcher");
+    assert!(
+        diff.iter().all(|c| matches!(c, ConfigChange::Added(..))),
+        "Expected the first diff to be entirely additions"
+    );
+
+    let latest = watcher.latest();
+    assert!(!latest.is_empty(), "Expected watcher to have a snapshot");
+
+    watcher.stop();
+}
+
+#[test]
+fn test_resource_cache_avoids_redundant_download() {
+    let dir = std::env::temp_dir().join("etf_matcher_vector_config_loader_test_cache");
+    std::fs::remove_dir_all(&dir).ok();
+    let cache = ResourceCache::new(&dir);
+
+    let url = get_symbol_map_url();
+    let first = get_resource_cached(&url, &cache).expect("Failed to fetch resource");
+    let second =
+        get_resource_cached(&url, &cache).expect("Failed to fetch resource from cache");
+    assert_eq!(first, second, "Cached resource should match the original download");
+
+    cache.clear().expect("Failed to clear cache");
+    assert!(!dir.exists(), "Expected cache directory to be removed");
+}
+
+#[test]
+fn test_verify_resource() {
+    let bytes = b"ticker-vector-bytes";
+    // A well-formed sha256 hex string that is not the digest of `bytes`.
+    let expected = "a6ae5c8f6a9a6e27cf0a310f1d7a11c4a6a3a8df94ed368d3afa7ec8d4bfb9d4";
+
+    // Mismatched checksum should be rejected regardless of its exact value.
+    assert!(verify_resource(bytes, expected).is_err());
+
+    // Correct checksum round-trips via the crate's own hex encoding.
+    let digest = sha2::Sha256::digest(bytes);
+    let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+    assert!(verify_resource(bytes, &hex).is_ok());
+    assert!(verify_resource(bytes, &hex.to_uppercase()).is_ok());
+}
+
+#[test]
+fn test_get_ticker_vectors_collection_by_key_verified_requires_checksum() {
+    // The remote config doesn't (yet) publish a sha256 for "default", so the strict
+    // verified getter must refuse rather than silently skipping verification.
+    let result = get_ticker_vectors_collection_by_key_verified("default");
+    assert!(
+        result.is_err(),
+        "Expected an error when the config has no sha256 to verify against"
+    );
+}
+
+#[test]
+fn test_get_ticker_vectors_collection_by_key_verified_missing_key() {
+    let result = get_ticker_vectors_collection_by_key_verified("nonexistent_key");
+    assert!(
+        result.is_err(),
+        "Expected an error when fetching a non-existent key but got Ok"
+    );
+}
+
+#[test]
+fn test_discover_local_config_from_walks_parent_directories() {
+    let root = std::env::temp_dir().join("etf_matcher_vector_config_loader_test_discover");
+    let nested = root.join("a").join("b");
+    std::fs::create_dir_all(&nested).expect("Failed to create nested test directories");
+    std::fs::write(
+        root.join("etf_matcher_vector_configs.toml"),
+        r#"
+[ticker_vector_config.offline]
+path = "offline.bin"
+"#,
+    )
+    .expect("Failed to write local config");
+
+    // Walking from `nested` (without touching the process's current directory) should
+    // find the file two levels up, in `root`.
+    let discovered = discover_local_config_from(&nested)
+        .expect("discover_local_config_from should not error")
+        .expect("Expected to find the local config in an ancestor directory");
+    assert_eq!(
+        discovered.file_name().unwrap(),
+        "etf_matcher_vector_configs.toml"
+    );
+    assert_eq!(discovered.parent().unwrap(), root);
+
+    let empty_root = std::env::temp_dir().join("etf_matcher_vector_config_loader_test_discover_empty");
+    std::fs::create_dir_all(&empty_root).expect("Failed to create empty test directory");
+    let not_found = discover_local_config_from(&empty_root)
+        .expect("discover_local_config_from should not error");
+    std::fs::remove_dir_all(&root).ok();
+    std::fs::remove_dir_all(&empty_root).ok();
+
+    assert!(
+        not_found.is_none(),
+        "Expected no local config to be found under an unrelated directory tree"
+    );
+}
+
+#[test]
+fn test_get_all_etf_matcher_configs_or_discover_offline_fixture() {
+    // Points discovery directly at the checked-in fixture directory, so this runs
+    // fully offline regardless of the process's current directory.
+    let fixtures_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+
+    let configs = get_all_etf_matcher_configs_or_discover_from(&fixtures_dir)
+        .expect("Failed to load the fixture config offline");
+
+    let fixture = configs
+        .get("offline-fixture")
+        .expect("Expected the fixture key to be present");
+    assert_eq!(fixture.path, "offline-fixture.bin");
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_get_all_etf_matcher_configs_async() {
+    let configs = get_all_etf_matcher_configs_async().await;
+
+    assert!(configs.is_ok(), "Failed to fetch configurations");
+
+    let configs = configs.unwrap();
+    assert!(
+        !configs.is_empty(),
+        "Expected some configurations but got an empty BTreeMap"
+    );
+}
+
+#[cfg(feature = "async")]
+#[tokio::test]
+async fn test_get_etf_matcher_config_by_key_async() {
+    let result = get_etf_matcher_config_by_key_async("default").await;
+
+    assert!(
+        result.is_ok(),
+        "Expected to fetch config for key 'default' but got an error"
+    );
+
+    let missing_result = get_etf_matcher_config_by_key_async("nonexistent_key").await;
+    assert!(
+        missing_result.is_err(),
+        "Expected an error when fetching a non-existent key but got Ok"
+    );
+}